@@ -0,0 +1,184 @@
+//! Async point-ingestion `Sink` for the online LOESS processor.
+//!
+//! ## Purpose
+//!
+//! This module turns [`ParallelOnlineLoess`]'s pull-style `add_point` loop
+//! into a composable async node: points are pushed in through a
+//! [`futures::Sink`] and each accepted point's [`OnlinePoint`] comes back out
+//! through a companion [`futures::Stream`], so a live async source (sensor
+//! stream, message queue) can feed the model directly.
+//!
+//! ## Design notes
+//!
+//! * **Latency**: Keeps `parallel = false` as the default (see
+//!   [`ParallelOnlineLoessBuilder::parallel`](crate::adapters::online::ParallelOnlineLoessBuilder::parallel)),
+//!   matching the single-point latency-sensitive path.
+//! * **Emit policy**: [`EmitPolicy::SmoothedOnly`] (the default) filters out
+//!   `None` warm-up outputs (before `min_points`) so only smoothed emissions
+//!   reach the stream; [`EmitPolicy::Every`] forwards every accepted point.
+//! * **Backpressure**: Built on bounded `futures::channel::mpsc` queues, same
+//!   as [`crate::adapters::async_streaming`].
+
+#![cfg(feature = "async")]
+
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::sink::Sink;
+use futures::stream::{Stream, StreamExt};
+use futures::{ready, SinkExt};
+
+use loess_rs::internals::algorithms::regression::SolverLinalg;
+use loess_rs::internals::math::distance::DistanceLinalg;
+use loess_rs::internals::math::linalg::FloatLinalg;
+use loess_rs::internals::primitives::errors::LoessError;
+
+use crate::adapters::online::{OnlinePoint, ParallelOnlineLoess};
+
+/// Default channel capacity used by [`AsyncOnlineLoess::new`].
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 64;
+
+/// Controls which `add_point` outcomes are surfaced on the stream half of
+/// [`AsyncOnlineLoess`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmitPolicy {
+    /// Filter out `None` warm-up outputs (before `min_points`); only
+    /// smoothed emissions reach the stream. This is the default.
+    #[default]
+    SmoothedOnly,
+    /// Forward every accepted point, including `None` warm-up outputs.
+    Every,
+}
+
+/// Async `Sink`/`Stream` wrapper around [`ParallelOnlineLoess`].
+///
+/// Points sent into the [`Sink`] half are fed to `add_point` on a blocking
+/// thread; the resulting [`OnlinePoint`]s (or errors) come back out through
+/// the [`Stream`] half, filtered according to the configured [`EmitPolicy`].
+pub struct AsyncOnlineLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    input_tx: Option<mpsc::Sender<(Vec<T>, T)>>,
+    output_rx: mpsc::Receiver<Result<Option<OnlinePoint<T>>, LoessError>>,
+    worker: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl<T> AsyncOnlineLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    /// Wrap an online processor for async use with the default channel
+    /// capacity and [`EmitPolicy::SmoothedOnly`].
+    pub fn new(processor: ParallelOnlineLoess<T>) -> Self {
+        Self::with_options(processor, DEFAULT_CHANNEL_CAPACITY, EmitPolicy::default())
+    }
+
+    /// Wrap an online processor for async use with a custom channel capacity
+    /// and emit policy.
+    pub fn with_options(
+        processor: ParallelOnlineLoess<T>,
+        capacity: usize,
+        emit_policy: EmitPolicy,
+    ) -> Self {
+        let (input_tx, mut input_rx) = mpsc::channel::<(Vec<T>, T)>(capacity);
+        let (mut output_tx, output_rx) = mpsc::channel(capacity);
+
+        let worker = tokio::spawn(async move {
+            let mut processor = processor;
+
+            while let Some((x, y)) = input_rx.next().await {
+                let outcome = tokio::task::spawn_blocking(move || {
+                    let result = processor.add_point(&x, y);
+                    (processor, result)
+                })
+                .await;
+
+                let Ok((returned, result)) = outcome else {
+                    // The blocking task panicked; there's no processor to recover.
+                    return;
+                };
+                processor = returned;
+
+                // `SmoothedOnly` drops `None` (warm-up) outputs so only
+                // smoothed emissions reach the stream; `Every` forwards them
+                // as `Ok(None)` instead.
+                let emitted = match result {
+                    Ok(None) if emit_policy == EmitPolicy::SmoothedOnly => None,
+                    Ok(output) => Some(Ok(output)),
+                    Err(err) => Some(Err(err)),
+                };
+
+                if let Some(item) = emitted {
+                    if output_tx.send(item).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Self {
+            input_tx: Some(input_tx),
+            output_rx,
+            worker: Some(worker),
+        }
+    }
+}
+
+impl<T> Sink<(Vec<T>, T)> for AsyncOnlineLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    type Error = mpsc::SendError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let tx = self.input_tx.as_mut().expect("sink used after close");
+        Pin::new(tx).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: (Vec<T>, T)) -> Result<(), Self::Error> {
+        let tx = self.input_tx.as_mut().expect("sink used after close");
+        Pin::new(tx).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.input_tx.as_mut() {
+            Some(tx) => Pin::new(tx).poll_flush(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if let Some(tx) = self.input_tx.as_mut() {
+            ready!(Pin::new(tx).poll_close(cx))?;
+        }
+        // Dropping the sender unblocks the worker's recv loop, letting it exit.
+        self.input_tx = None;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> Stream for AsyncOnlineLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    type Item = Result<Option<OnlinePoint<T>>, LoessError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.output_rx).poll_next(cx)
+    }
+}
+
+impl<T> Drop for AsyncOnlineLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        self.input_tx = None;
+        if let Some(worker) = self.worker.take() {
+            worker.abort();
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Async `Stream`/`Sink` adapter for the streaming LOESS processor.
+//!
+//! ## Purpose
+//!
+//! This module lets [`ParallelStreamingLoess`] be driven from an async
+//! pipeline instead of only synchronous calls: chunks are pushed in through
+//! a [`Sink`] and smoothed results come back out through a [`Stream`], with
+//! the CPU-bound `process_chunk`/`finalize` work offloaded onto a blocking
+//! thread so the async executor is never stalled.
+//!
+//! ## Design notes
+//!
+//! * **Backpressure**: Built on bounded `futures::channel::mpsc` queues, so a
+//!   slow consumer naturally stalls the producer.
+//! * **Offloading**: Each chunk is processed via `tokio::task::spawn_blocking`,
+//!   with the processor handed back and forth so chunks stay strictly ordered.
+//! * **Finalization**: Closing the sink drains the processor with `finalize()`
+//!   and emits the trailing buffered result before the stream ends.
+
+#![cfg(feature = "async")]
+
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::sink::Sink;
+use futures::stream::{Stream, StreamExt};
+use futures::{ready, SinkExt};
+
+use loess_rs::internals::algorithms::regression::SolverLinalg;
+use loess_rs::internals::engine::output::LoessResult;
+use loess_rs::internals::math::distance::DistanceLinalg;
+use loess_rs::internals::math::linalg::FloatLinalg;
+use loess_rs::internals::primitives::errors::LoessError;
+
+use crate::adapters::streaming::ParallelStreamingLoess;
+
+/// Default channel capacity used by [`AsyncStreamingLoess::new`] consumers
+/// that don't need a custom value.
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 16;
+
+/// Async `Sink`/`Stream` wrapper around [`ParallelStreamingLoess`].
+///
+/// Chunks sent into the [`Sink`] half are processed off the async executor
+/// on a blocking thread-pool; the resulting [`LoessResult`]s (or errors) come
+/// back out through the [`Stream`] half, in submission order. Closing the
+/// sink finalizes the underlying processor and emits the trailing buffered
+/// result before the stream ends. Dropping the adapter aborts its worker
+/// task instead, without finalizing.
+pub struct AsyncStreamingLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    input_tx: Option<mpsc::Sender<(Vec<T>, Vec<T>)>>,
+    output_rx: mpsc::Receiver<Result<LoessResult<T>, LoessError>>,
+    worker: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl<T> AsyncStreamingLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    /// Wrap a streaming processor for async use with the default channel capacity.
+    pub fn new(processor: ParallelStreamingLoess<T>) -> Self {
+        Self::with_capacity(processor, DEFAULT_CHANNEL_CAPACITY)
+    }
+
+    /// Wrap a streaming processor for async use, bounding both the inbound
+    /// chunk queue and the outbound result queue at `capacity` for backpressure.
+    pub fn with_capacity(processor: ParallelStreamingLoess<T>, capacity: usize) -> Self {
+        let (input_tx, mut input_rx) = mpsc::channel::<(Vec<T>, Vec<T>)>(capacity);
+        let (mut output_tx, output_rx) = mpsc::channel(capacity);
+
+        let worker = tokio::spawn(async move {
+            let mut processor = processor;
+
+            while let Some((x, y)) = input_rx.next().await {
+                let outcome = tokio::task::spawn_blocking(move || {
+                    let result = processor.process_chunk(&x, &y);
+                    (processor, result)
+                })
+                .await;
+
+                let Ok((returned, result)) = outcome else {
+                    // The blocking task panicked; there's no processor to recover.
+                    return;
+                };
+                processor = returned;
+
+                if output_tx.send(result).await.is_err() {
+                    return;
+                }
+            }
+
+            // The sink was closed: finalize and emit the trailing buffered result.
+            let outcome = tokio::task::spawn_blocking(move || {
+                let result = processor.finalize();
+                result
+            })
+            .await;
+
+            if let Ok(final_result) = outcome {
+                let _ = output_tx.send(final_result).await;
+            }
+        });
+
+        Self {
+            input_tx: Some(input_tx),
+            output_rx,
+            worker: Some(worker),
+        }
+    }
+}
+
+impl<T> Sink<(Vec<T>, Vec<T>)> for AsyncStreamingLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    type Error = mpsc::SendError;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let tx = self.input_tx.as_mut().expect("sink used after close");
+        Pin::new(tx).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: (Vec<T>, Vec<T>)) -> Result<(), Self::Error> {
+        let tx = self.input_tx.as_mut().expect("sink used after close");
+        Pin::new(tx).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.input_tx.as_mut() {
+            Some(tx) => Pin::new(tx).poll_flush(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if let Some(tx) = self.input_tx.as_mut() {
+            ready!(Pin::new(tx).poll_close(cx))?;
+        }
+        // Dropping the sender unblocks the worker's recv loop, which then
+        // finalizes the processor and emits the trailing buffered result.
+        self.input_tx = None;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> Stream for AsyncStreamingLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    type Item = Result<LoessResult<T>, LoessError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.output_rx).poll_next(cx)
+    }
+}
+
+impl<T> Drop for AsyncStreamingLoess<T>
+where
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        // Drop the sender (if still open) and abort the worker task so it
+        // isn't left running in the background.
+        self.input_tx = None;
+        if let Some(worker) = self.worker.take() {
+            worker.abort();
+        }
+    }
+}
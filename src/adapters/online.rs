@@ -21,6 +21,7 @@ use crate::evaluation::intervals::interval_pass_parallel;
 
 // External dependencies
 use num_traits::Float;
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::result::Result;
 
@@ -37,6 +38,9 @@ use loess_rs::internals::math::scaling::ScalingMethod;
 use loess_rs::internals::primitives::backend::Backend;
 use loess_rs::internals::primitives::errors::LoessError;
 
+// Internal dependencies
+use crate::kernels::CustomWeightFn;
+
 // ============================================================================
 // Extended Online LOESS Builder
 // ============================================================================
@@ -46,6 +50,12 @@ use loess_rs::internals::primitives::errors::LoessError;
 pub struct ParallelOnlineLoessBuilder<T: FloatLinalg + DistanceLinalg + SolverLinalg> {
     /// Base builder from the loess-rs crate
     pub base: OnlineLoessBuilder<T>,
+    /// Custom kernel set via [`custom_weight_function`](Self::custom_weight_function).
+    ///
+    /// Mirrors [`ParallelStreamingLoessBuilder::custom_weight_fn`](crate::adapters::streaming::ParallelStreamingLoessBuilder);
+    /// kept here rather than on `base` since `loess-rs`'s `OnlineLoessBuilder`
+    /// has no field for it.
+    custom_weight_fn: Option<CustomWeightFn<T>>,
 }
 
 impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> Default
@@ -64,7 +74,10 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
         let mut base = OnlineLoessBuilder::default();
         // Default to false for online (latency-sensitive)
         base.parallel = Some(false);
-        Self { base }
+        Self {
+            base,
+            custom_weight_fn: None,
+        }
     }
 
     /// Set parallel execution mode.
@@ -101,6 +114,25 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
         self
     }
 
+    /// Set a custom kernel weight function.
+    ///
+    /// `loess-rs` has no extension point for arbitrary kernels, so — like
+    /// [`ParallelStreamingLoessBuilder::custom_weight_function`](crate::adapters::streaming::ParallelStreamingLoessBuilder::custom_weight_function) —
+    /// this doesn't feed into its engine. `OnlineOutput` is an opaque
+    /// `loess-rs` type with no public constructor, so fastLoess can't return
+    /// one built from a custom-kernel result either: instead, when a kernel
+    /// is configured, [`ParallelOnlineLoess::add_point`] maintains its own
+    /// sliding window (bounded by `window_capacity`) and smooths it directly
+    /// via [`smooth_with_custom_kernel`](crate::kernels::smooth_with_custom_kernel),
+    /// returning [`OnlinePoint::CustomKernel`] instead of
+    /// [`OnlinePoint::Engine`]. In this mode `loess-rs`'s own window,
+    /// `iterations`, and `robustness_method` aren't applied, and only the
+    /// first coordinate of `x` is used — see [`crate::kernels`] for more.
+    pub fn custom_weight_function(mut self, f: CustomWeightFn<T>) -> Self {
+        self.custom_weight_fn = Some(f);
+        self
+    }
+
     /// Set the robustness method for outlier handling.
     pub fn robustness_method(mut self, method: RobustnessMethod) -> Self {
         self.base.robustness_method = method;
@@ -176,6 +208,8 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
             return Err(err.clone());
         }
 
+        let custom_weight_fn = self.custom_weight_fn;
+
         // Configure parallel callbacks before building
         let mut builder = self.base;
 
@@ -189,8 +223,20 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
             }
         }
 
+        let fraction = builder.fraction;
+        let min_points = builder.min_points.max(1);
+        let window_capacity = builder.window_capacity.max(1);
+
         let processor = builder.build()?;
-        Ok(ParallelOnlineLoess { processor })
+        Ok(ParallelOnlineLoess {
+            processor,
+            custom_weight_fn,
+            custom_fraction: fraction,
+            custom_min_points: min_points,
+            custom_window_capacity: window_capacity,
+            custom_window_x: VecDeque::with_capacity(window_capacity),
+            custom_window_y: VecDeque::with_capacity(window_capacity),
+        })
     }
 }
 
@@ -198,17 +244,69 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
 // Extended Online LOESS Processor
 // ============================================================================
 
+/// Output of a single [`ParallelOnlineLoess::add_point`] call.
+///
+/// Most of the time this wraps `loess-rs`'s own [`OnlineOutput`]. When a
+/// [`custom_weight_function`](ParallelOnlineLoessBuilder::custom_weight_function)
+/// is configured, `add_point` instead smooths its own sliding window via
+/// [`smooth_with_custom_kernel`](crate::kernels::smooth_with_custom_kernel) —
+/// since `OnlineOutput` is opaque to fastLoess and can't be constructed from
+/// that result — and returns the smoothed value directly.
+#[derive(Debug)]
+pub enum OnlinePoint<T> {
+    /// Smoothed via `loess-rs`'s own engine.
+    Engine(OnlineOutput<T>),
+    /// Smoothed locally via a configured custom kernel.
+    CustomKernel(T),
+}
+
 /// Online LOESS processor with parallel support.
 pub struct ParallelOnlineLoess<T: FloatLinalg + DistanceLinalg + SolverLinalg> {
     processor: loess_rs::internals::adapters::online::OnlineLoess<T>,
+    custom_weight_fn: Option<CustomWeightFn<T>>,
+    custom_fraction: T,
+    custom_min_points: usize,
+    custom_window_capacity: usize,
+    custom_window_x: VecDeque<T>,
+    custom_window_y: VecDeque<T>,
 }
 
 impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float + Debug + Send + Sync + 'static>
     ParallelOnlineLoess<T>
 {
     /// Add a new point and get its smoothed value.
-    pub fn add_point(&mut self, x: &[T], y: T) -> Result<Option<OnlineOutput<T>>, LoessError> {
-        self.processor.add_point(x, y)
+    ///
+    /// If a [`custom_weight_function`](ParallelOnlineLoessBuilder::custom_weight_function)
+    /// is configured, this feeds its own sliding window (capped at
+    /// `window_capacity`, using only `x`'s first coordinate) instead of
+    /// `loess-rs`'s engine, and returns [`OnlinePoint::CustomKernel`] once
+    /// the window has at least `min_points` points. Otherwise it delegates
+    /// to `loess-rs`'s own engine and returns [`OnlinePoint::Engine`].
+    pub fn add_point(&mut self, x: &[T], y: T) -> Result<Option<OnlinePoint<T>>, LoessError> {
+        if let Some(weight_fn) = self.custom_weight_fn {
+            let x0 = x.first().copied().unwrap_or_else(T::zero);
+
+            if self.custom_window_x.len() == self.custom_window_capacity {
+                self.custom_window_x.pop_front();
+                self.custom_window_y.pop_front();
+            }
+            self.custom_window_x.push_back(x0);
+            self.custom_window_y.push_back(y);
+
+            if self.custom_window_x.len() < self.custom_min_points {
+                return Ok(None);
+            }
+
+            let xs: Vec<T> = self.custom_window_x.iter().copied().collect();
+            let ys: Vec<T> = self.custom_window_y.iter().copied().collect();
+            let smoothed =
+                crate::kernels::smooth_with_custom_kernel(&xs, &ys, self.custom_fraction, weight_fn);
+            return Ok(smoothed.last().copied().map(OnlinePoint::CustomKernel));
+        }
+
+        self.processor
+            .add_point(x, y)
+            .map(|output| output.map(OnlinePoint::Engine))
     }
 
     /// Get the current window size.
@@ -22,8 +22,12 @@ use crate::evaluation::intervals::interval_pass_parallel;
 
 // External dependencies
 use num_traits::Float;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::fmt::Debug;
 use std::result::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 // Export dependencies from loess-rs crate
 use loess_rs::internals::adapters::streaming::{MergeStrategy, StreamingLoessBuilder};
@@ -41,16 +45,30 @@ use loess_rs::internals::primitives::errors::LoessError;
 
 // Internal dependencies
 use crate::input::LoessInput;
+use crate::kernels::CustomWeightFn;
 
 // ============================================================================
 // Extended Streaming LOESS Builder
 // ============================================================================
 
+/// Default bound on concurrently in-flight chunks for pipelined processing.
+const DEFAULT_MAX_IN_FLIGHT: usize = 4;
+
 /// Builder for streaming LOESS processor with parallel support.
 #[derive(Debug, Clone)]
 pub struct ParallelStreamingLoessBuilder<T: FloatLinalg + DistanceLinalg + SolverLinalg> {
     /// Base builder from the loess-rs crate
     pub base: StreamingLoessBuilder<T>,
+    /// Bound on concurrently in-flight chunks for [`ParallelStreamingLoess::process_chunks_pipelined`].
+    max_in_flight: Option<usize>,
+    /// Custom kernel set via [`custom_weight_function`](Self::custom_weight_function).
+    ///
+    /// `loess-rs`'s `StreamingLoessBuilder` has no field for this — it only
+    /// exposes the fixed [`WeightFunction`](crate::api::WeightFunction) enum
+    /// — so the closure is kept here and applied by fastLoess itself via
+    /// [`crate::kernels::smooth_with_custom_kernel`] rather than threaded
+    /// into `base`.
+    custom_weight_fn: Option<CustomWeightFn<T>>,
 }
 
 impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> Default
@@ -68,7 +86,11 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
     fn new() -> Self {
         let mut base = StreamingLoessBuilder::default();
         base.parallel = Some(true); // Default to parallel in fastLoess
-        Self { base }
+        Self {
+            base,
+            max_in_flight: None,
+            custom_weight_fn: None,
+        }
     }
 
     /// Set parallel execution mode.
@@ -105,6 +127,38 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
         self
     }
 
+    /// Set a custom kernel weight function.
+    ///
+    /// **This replaces the fit entirely — it is not `loess-rs`'s polynomial
+    /// regression with a different kernel plugged in.** `loess-rs` has no
+    /// extension point for arbitrary kernels, so when set,
+    /// [`process_chunk`](ParallelStreamingLoess::process_chunk) instead
+    /// computes the chunk's output via
+    /// [`smooth_with_custom_kernel`](crate::kernels::smooth_with_custom_kernel),
+    /// an O(n² log n)-per-chunk local weighted average computed entirely
+    /// within fastLoess. `polynomial_degree`, `robustness_method`,
+    /// `iterations`, and `boundary_policy` are silently inapplicable to that
+    /// path, and `overlap`, `auto_converge`, `compute_residuals`,
+    /// `return_robustness_weights`, and `return_diagnostics` are rejected by
+    /// [`build`](Self::build) (see Panics below) — the
+    /// returned [`LoessResult`] still echoes back config fields like
+    /// `polynomial_degree`, but nothing downstream of the weighted average
+    /// actually used them. See [`crate::kernels`] for the expected kernel
+    /// shape and a ready-made [`hat_convolution`](crate::kernels::hat_convolution)
+    /// kernel.
+    ///
+    /// # Panics
+    ///
+    /// [`build`](Self::build) panics if this is combined with `overlap`,
+    /// `compute_residuals`, `return_robustness_weights`, `return_diagnostics`,
+    /// or `auto_converge` — those options configure engine behavior the
+    /// custom-kernel path can't honor, so silently accepting them would mean
+    /// silently dropping them.
+    pub fn custom_weight_function(mut self, f: CustomWeightFn<T>) -> Self {
+        self.custom_weight_fn = Some(f);
+        self
+    }
+
     /// Set the robustness method for outlier handling.
     pub fn robustness_method(mut self, method: RobustnessMethod) -> Self {
         self.base.robustness_method = method;
@@ -175,22 +229,67 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync>
         self
     }
 
+    /// Bound how many chunks [`ParallelStreamingLoess::process_chunks_pipelined`]
+    /// hands to the rayon pool at once. Defaults to [`DEFAULT_MAX_IN_FLIGHT`].
+    pub fn max_in_flight(mut self, n: usize) -> Self {
+        self.max_in_flight = Some(n);
+        self
+    }
+
     // ========================================================================
     // Build Method
     // ========================================================================
 
     /// Build the streaming processor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`custom_weight_function`](Self::custom_weight_function) is
+    /// combined with `overlap`, `compute_residuals`,
+    /// `return_robustness_weights`, `return_diagnostics`, or
+    /// `auto_converge` — see that setter's docs for why those options are
+    /// incompatible with the custom-kernel path.
     pub fn build(self) -> Result<ParallelStreamingLoess<T>, LoessError> {
         // Check for deferred errors from adapter conversion
         if let Some(ref err) = self.base.deferred_error {
             return Err(err.clone());
         }
 
+        if self.custom_weight_fn.is_some() {
+            assert!(
+                self.base.overlap == 0
+                    && !self.base.compute_residuals
+                    && !self.base.return_robustness_weights
+                    && !self.base.return_diagnostics
+                    && self.base.auto_convergence.is_none(),
+                "custom_weight_function is incompatible with overlap, compute_residuals, \
+                 return_robustness_weights, return_diagnostics, and auto_converge: the \
+                 custom-kernel path (smooth_with_custom_kernel) is a single-pass local \
+                 average that can't honor any of them, so the result would silently omit \
+                 what they configure. Drop the custom kernel or these options."
+            );
+        }
+
         Ok(ParallelStreamingLoess {
             config: self,
             processor: None,
         })
     }
+
+    /// Build the streaming processor along with an [`AbortHandle`] that can
+    /// cooperatively cancel the run at the next chunk boundary.
+    pub fn build_abortable(self) -> Result<(AbortableStreamingLoess<T>, AbortHandle), LoessError> {
+        let aborted = Arc::new(AtomicBool::new(false));
+        let inner = self.build()?;
+
+        Ok((
+            AbortableStreamingLoess {
+                inner,
+                aborted: aborted.clone(),
+            },
+            AbortHandle { aborted },
+        ))
+    }
 }
 
 // ============================================================================
@@ -209,6 +308,12 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float + Debug + Send + Syn
     ParallelStreamingLoess<T>
 {
     /// Process a chunk of data.
+    ///
+    /// If [`custom_weight_function`](ParallelStreamingLoessBuilder::custom_weight_function)
+    /// is configured, this bypasses `loess-rs`'s engine entirely and smooths
+    /// via [`smooth_with_custom_kernel`](crate::kernels::smooth_with_custom_kernel)
+    /// instead — see that setter's docs for which options this silently
+    /// can't honor.
     pub fn process_chunk<I1, I2>(&mut self, x: &I1, y: &I2) -> Result<LoessResult<T>, LoessError>
     where
         I1: LoessInput<T> + ?Sized,
@@ -217,6 +322,40 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float + Debug + Send + Syn
         let x_slice = x.as_loess_slice()?;
         let y_slice = y.as_loess_slice()?;
 
+        if let Some(weight_fn) = self.config.custom_weight_fn {
+            let smoothed = crate::kernels::smooth_with_custom_kernel(
+                x_slice,
+                y_slice,
+                self.config.base.fraction,
+                weight_fn,
+            );
+
+            return Ok(LoessResult {
+                x: x_slice.to_vec(),
+                dimensions: self.config.base.dimensions,
+                distance_metric: self.config.base.distance_metric.clone(),
+                polynomial_degree: self.config.base.polynomial_degree,
+                y: smoothed,
+                standard_errors: None,
+                confidence_lower: None,
+                confidence_upper: None,
+                prediction_lower: None,
+                prediction_upper: None,
+                residuals: None,
+                robustness_weights: None,
+                diagnostics: None,
+                iterations_used: None,
+                fraction_used: self.config.base.fraction,
+                cv_scores: None,
+                enp: None,
+                trace_hat: None,
+                delta1: None,
+                delta2: None,
+                residual_scale: None,
+                leverage: None,
+            });
+        }
+
         // Lazily initialize the processor with parallel callbacks
         if self.processor.is_none() {
             let mut builder = self.config.base.clone();
@@ -279,4 +418,424 @@ impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float + Debug + Send + Syn
             proc.reset();
         }
     }
+
+    /// Process many chunks in pipelined mode.
+    ///
+    /// Every chunk's LOESS fit is dispatched onto the rayon pool independently
+    /// — when `overlap` is non-zero, a chunk is first augmented with the
+    /// trailing `overlap` raw points borrowed from the *previous* chunk, so
+    /// the fit gets the same boundary context a sequential run would have
+    /// given it, without needing `&mut self` access to a single retained
+    /// processor. Up to `max_in_flight` (see
+    /// [`ParallelStreamingLoessBuilder::max_in_flight`]) chunks are genuinely
+    /// in flight on the pool at once; results are released through a
+    /// sequence-numbered reorder buffer so output order matches submission
+    /// order regardless of completion order. As each chunk is released, the
+    /// *previous* chunk's trailing `overlap` estimates are reconciled against
+    /// this chunk's boundary-aware estimates for the same positions, using
+    /// the builder's configured [`MergeStrategy`] — that reconciliation is
+    /// the only serial work at each chunk boundary; the fits themselves run
+    /// concurrently.
+    ///
+    /// This does not call into `loess-rs`'s own internal overlap/merge
+    /// plumbing (which requires exclusive, in-order access to a single
+    /// retained processor and so can't itself be parallelized): it's a
+    /// self-contained reimplementation of the overlap contract. As a result,
+    /// output is only guaranteed bit-for-bit identical to the sequential
+    /// [`process_chunk`](Self::process_chunk) path when `overlap == 0` (where
+    /// there is nothing to merge); with `overlap != 0` this is a
+    /// boundary-aware approximation, not a guaranteed match — in particular
+    /// [`MergeStrategy::WeightedAverage`] is approximated with a linear
+    /// crossfade across the overlap window, since `loess-rs`'s exact
+    /// weighting curve isn't visible to fastLoess.
+    #[cfg(feature = "cpu")]
+    pub fn process_chunks_pipelined(
+        &mut self,
+        chunks: impl IntoIterator<Item = (Vec<T>, Vec<T>)>,
+    ) -> Vec<Result<LoessResult<T>, LoessError>> {
+        let chunks: Vec<(Vec<T>, Vec<T>)> = chunks.into_iter().collect();
+        self.process_chunks_pipelined_parallel(chunks)
+    }
+
+    /// Genuinely concurrent implementation backing
+    /// [`process_chunks_pipelined`](Self::process_chunks_pipelined); see its
+    /// docs for the overlap-handling contract.
+    #[cfg(feature = "cpu")]
+    fn process_chunks_pipelined_parallel(
+        &self,
+        chunks: Vec<(Vec<T>, Vec<T>)>,
+    ) -> Vec<Result<LoessResult<T>, LoessError>> {
+        let overlap = self.config.base.overlap;
+        let merge_strategy = &self.config.base.merge_strategy;
+        let max_in_flight = self
+            .config
+            .max_in_flight
+            .unwrap_or(DEFAULT_MAX_IN_FLIGHT)
+            .max(1);
+
+        let mut out: Vec<Result<LoessResult<T>, LoessError>> = Vec::with_capacity(chunks.len());
+        let mut next_emit = 0u64;
+
+        for (batch_start, batch) in chunks.chunks(max_in_flight).enumerate() {
+            let batch_start = batch_start as u64 * max_in_flight as u64;
+            let (tx, rx) = std::sync::mpsc::channel();
+
+            // Each chunk in the batch is dispatched onto its own freshly
+            // built processor (augmented with borrowed boundary context, see
+            // the doc above), so these genuinely run concurrently on the
+            // rayon pool instead of serializing on a single `&mut self`.
+            rayon::scope(|scope| {
+                for (i, (x, y)) in batch.iter().enumerate() {
+                    let seq = batch_start + i as u64;
+                    let global_idx = seq as usize;
+                    let prefix_len = if global_idx == 0 {
+                        0
+                    } else {
+                        overlap.min(chunks[global_idx - 1].0.len())
+                    };
+
+                    let mut aug_x = Vec::with_capacity(prefix_len + x.len());
+                    let mut aug_y = Vec::with_capacity(prefix_len + y.len());
+                    if prefix_len > 0 {
+                        let (px, py) = &chunks[global_idx - 1];
+                        aug_x.extend_from_slice(&px[px.len() - prefix_len..]);
+                        aug_y.extend_from_slice(&py[py.len() - prefix_len..]);
+                    }
+                    aug_x.extend_from_slice(x);
+                    aug_y.extend_from_slice(y);
+
+                    let tx = tx.clone();
+                    let mut config = self.config.clone();
+                    // Fit the augmented window standalone; the cross-chunk
+                    // blend happens afterward via `merge_strategy`, not
+                    // loess-rs's own overlap plumbing.
+                    config.base.overlap = 0;
+                    scope.spawn(move |_| {
+                        let result = match config.build() {
+                            Ok(mut processor) => processor.process_chunk(&aug_x, &aug_y),
+                            Err(err) => Err(err),
+                        };
+                        let _ = tx.send((seq, prefix_len, result));
+                    });
+                }
+            });
+            drop(tx);
+
+            // Workers finish out of order; the reorder buffer releases them
+            // strictly in submission order, so the merge below only ever
+            // looks at an already-released previous chunk.
+            let mut reorder: BinaryHeap<Reverse<SequencedResult<T>>> = BinaryHeap::new();
+            for (seq, prefix_len, result) in rx {
+                reorder.push(Reverse(SequencedResult { seq, prefix_len, result }));
+            }
+            while let Some(Reverse(ready)) = reorder.peek() {
+                if ready.seq != next_emit {
+                    break;
+                }
+                let Reverse(ready) = reorder.pop().expect("peeked entry must exist");
+                let SequencedResult {
+                    prefix_len, result, ..
+                } = ready;
+
+                match result {
+                    Ok(full) => {
+                        if prefix_len > 0 {
+                            if let Some(Ok(prev)) = out.last_mut() {
+                                merge_overlap_tail(prev, &full, prefix_len, merge_strategy);
+                            }
+                        }
+                        out.push(Ok(slice_result_from(full, prefix_len)));
+                    }
+                    Err(err) => out.push(Err(err)),
+                }
+                next_emit += 1;
+            }
+            debug_assert!(reorder.is_empty(), "reorder buffer must drain every batch");
+        }
+
+        out
+    }
+}
+
+/// A chunk result tagged with its submission order and the length of the
+/// boundary context borrowed from the previous chunk.
+///
+/// Used by [`ParallelStreamingLoess::process_chunks_pipelined`]'s reorder
+/// buffer to release results strictly in submission order, regardless of
+/// which order the rayon pool happened to finish preparing them in, and to
+/// know how much of the fit to fold into the previous chunk's merge.
+#[cfg(feature = "cpu")]
+struct SequencedResult<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> {
+    seq: u64,
+    prefix_len: usize,
+    result: Result<LoessResult<T>, LoessError>,
+}
+
+#[cfg(feature = "cpu")]
+impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> PartialEq
+    for SequencedResult<T>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.seq == other.seq
+    }
+}
+
+#[cfg(feature = "cpu")]
+impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> Eq
+    for SequencedResult<T>
+{
+}
+
+#[cfg(feature = "cpu")]
+impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> PartialOrd
+    for SequencedResult<T>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "cpu")]
+impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync> Ord
+    for SequencedResult<T>
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.seq.cmp(&other.seq)
+    }
+}
+
+/// Reconcile `prev`'s trailing `prefix_len` estimates against `head`'s
+/// leading `prefix_len` estimates for the same boundary positions, in place,
+/// using `strategy`. Used by
+/// [`ParallelStreamingLoess::process_chunks_pipelined`] once a chunk's
+/// boundary-aware fit is available, to merge it into the previous chunk's
+/// already-released result.
+#[cfg(feature = "cpu")]
+fn merge_overlap_tail<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float>(
+    prev: &mut LoessResult<T>,
+    head: &LoessResult<T>,
+    prefix_len: usize,
+    strategy: &MergeStrategy,
+) {
+    let prev_len = prev.y.len();
+    if prefix_len == 0 || prev_len < prefix_len || head.y.len() < prefix_len {
+        return;
+    }
+    let start = prev_len - prefix_len;
+
+    merge_slice(&mut prev.y[start..], &head.y[..prefix_len], strategy);
+    merge_opt(&mut prev.standard_errors, &head.standard_errors, start, prefix_len, strategy);
+    merge_opt(&mut prev.confidence_lower, &head.confidence_lower, start, prefix_len, strategy);
+    merge_opt(&mut prev.confidence_upper, &head.confidence_upper, start, prefix_len, strategy);
+    merge_opt(&mut prev.prediction_lower, &head.prediction_lower, start, prefix_len, strategy);
+    merge_opt(&mut prev.prediction_upper, &head.prediction_upper, start, prefix_len, strategy);
+    merge_opt(&mut prev.residuals, &head.residuals, start, prefix_len, strategy);
+    merge_opt(&mut prev.robustness_weights, &head.robustness_weights, start, prefix_len, strategy);
+    merge_opt(&mut prev.leverage, &head.leverage, start, prefix_len, strategy);
+}
+
+/// Merge one optional per-point field of a boundary pair, if both sides have
+/// it and are long enough; fields that are `None` or too short (e.g. not
+/// requested via `compute_residuals`/`return_robustness_weights`) are left
+/// untouched.
+#[cfg(feature = "cpu")]
+fn merge_opt<T: Float>(
+    prev: &mut Option<Vec<T>>,
+    head: &Option<Vec<T>>,
+    start: usize,
+    prefix_len: usize,
+    strategy: &MergeStrategy,
+) {
+    if let (Some(prev_vec), Some(head_vec)) = (prev.as_mut(), head.as_ref()) {
+        if prev_vec.len() >= start + prefix_len && head_vec.len() >= prefix_len {
+            merge_slice(&mut prev_vec[start..start + prefix_len], &head_vec[..prefix_len], strategy);
+        }
+    }
+}
+
+/// Blend a previous chunk's tail estimates with a following chunk's head
+/// estimates for the same boundary positions, in place, per
+/// [`MergeStrategy`].
+///
+/// `loess-rs`'s own merge logic is internal to its opaque streaming engine,
+/// so this is fastLoess's own reimplementation of the same contract:
+/// [`MergeStrategy::TakeFirst`] and [`MergeStrategy::Average`] match the
+/// strategy name exactly, but [`MergeStrategy::WeightedAverage`]'s precise
+/// weighting curve isn't visible to fastLoess — it's approximated here with a
+/// linear crossfade across the overlap window.
+#[cfg(feature = "cpu")]
+fn merge_slice<T: Float>(prev_tail: &mut [T], head: &[T], strategy: &MergeStrategy) {
+    let n = prev_tail.len();
+    for (k, (a, &b)) in prev_tail.iter_mut().zip(head).enumerate() {
+        *a = match strategy {
+            MergeStrategy::TakeFirst => *a,
+            MergeStrategy::Average => (*a + b) / T::from(2.0).unwrap(),
+            MergeStrategy::WeightedAverage => {
+                let w_head = T::from((k + 1) as f64 / (n + 1) as f64).unwrap();
+                let w_prev = T::one() - w_head;
+                w_prev * (*a) + w_head * b
+            }
+            // `MergeStrategy` may grow variants we don't know about; default
+            // to keeping the earlier estimate rather than guessing.
+            #[allow(unreachable_patterns)]
+            _ => *a,
+        };
+    }
+}
+
+/// Drop the leading `skip` entries from every per-point field of a
+/// [`LoessResult`], leaving scalar/aggregate fields (e.g. `fraction_used`,
+/// `enp`, `cv_scores`) untouched.
+///
+/// Used to trim a chunk's borrowed boundary prefix back off before returning
+/// its fit, once that prefix has done its job of giving the fit boundary
+/// context (and, for the first `prefix_len` entries, feeding the merge in
+/// [`merge_overlap_tail`]).
+#[cfg(feature = "cpu")]
+fn slice_result_from<T: FloatLinalg + DistanceLinalg + SolverLinalg>(
+    result: LoessResult<T>,
+    skip: usize,
+) -> LoessResult<T> {
+    fn skip_vec<U>(mut v: Vec<U>, skip: usize) -> Vec<U> {
+        if skip >= v.len() {
+            Vec::new()
+        } else {
+            v.split_off(skip)
+        }
+    }
+    fn skip_opt<U>(v: Option<Vec<U>>, skip: usize) -> Option<Vec<U>> {
+        v.map(|v| skip_vec(v, skip))
+    }
+
+    LoessResult {
+        x: skip_vec(result.x, skip),
+        y: skip_vec(result.y, skip),
+        standard_errors: skip_opt(result.standard_errors, skip),
+        confidence_lower: skip_opt(result.confidence_lower, skip),
+        confidence_upper: skip_opt(result.confidence_upper, skip),
+        prediction_lower: skip_opt(result.prediction_lower, skip),
+        prediction_upper: skip_opt(result.prediction_upper, skip),
+        residuals: skip_opt(result.residuals, skip),
+        robustness_weights: skip_opt(result.robustness_weights, skip),
+        leverage: skip_opt(result.leverage, skip),
+        ..result
+    }
+}
+
+// ============================================================================
+// Cooperative Cancellation
+// ============================================================================
+
+/// Error produced by an [`AbortableStreamingLoess`] run.
+///
+/// `loess-rs`'s [`LoessError`] is an external type fastLoess can't add a
+/// variant to, so — unlike the request's literal ask for a
+/// `LoessError::Aborted` variant — this is a separate fastLoess-level error
+/// type that wraps it instead. Callers using [`build_abortable`](ParallelStreamingLoessBuilder::build_abortable)
+/// therefore see a different error type than the non-abortable
+/// [`process_chunk`](ParallelStreamingLoess::process_chunk) path.
+#[derive(Debug, Clone)]
+pub enum StreamingRunError {
+    /// Error surfaced by the underlying loess-rs engine.
+    Loess(LoessError),
+    /// The run was stopped via the paired [`AbortHandle::abort`].
+    Aborted,
+}
+
+impl std::fmt::Display for StreamingRunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Loess(err) => write!(f, "{err}"),
+            Self::Aborted => write!(f, "streaming run was aborted via AbortHandle::abort"),
+        }
+    }
+}
+
+impl std::error::Error for StreamingRunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Loess(err) => Some(err),
+            Self::Aborted => None,
+        }
+    }
+}
+
+/// Handle used to cooperatively cancel a streaming run created via
+/// [`ParallelStreamingLoessBuilder::build_abortable`].
+///
+/// Modeled on `futures::future::Abortable`: calling [`abort`](Self::abort)
+/// sets a shared flag that [`AbortableStreamingLoess::process_chunk`] checks
+/// at the start of every chunk, so a client disconnect or a deadline can stop
+/// a long-running job without panicking or leaving it hanging.
+///
+/// ## Limitation
+///
+/// The flag is only checked at chunk boundaries, not between the rayon
+/// sub-passes (smooth/CV/interval/vertex) that run *inside* a single
+/// `process_chunk` call — those sub-passes live in `loess-rs`'s internal
+/// engine, which fastLoess has no hook into mid-chunk. A very large chunk
+/// therefore still runs to completion once started; only the *next* chunk
+/// is skipped after an abort. Keep chunks small if sub-chunk cancellation
+/// latency matters.
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    aborted: Arc<AtomicBool>,
+}
+
+impl AbortHandle {
+    /// Signal the paired streaming run to stop at the next chunk boundary.
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+    }
+
+    /// Check whether [`abort`](Self::abort) has already been called.
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+}
+
+/// Streaming LOESS processor that checks a shared abort flag at chunk
+/// boundaries, created via [`ParallelStreamingLoessBuilder::build_abortable`].
+pub struct AbortableStreamingLoess<
+    T: FloatLinalg + DistanceLinalg + SolverLinalg + Debug + Send + Sync,
+> {
+    inner: ParallelStreamingLoess<T>,
+    aborted: Arc<AtomicBool>,
+}
+
+impl<T: FloatLinalg + DistanceLinalg + SolverLinalg + Float + Debug + Send + Sync + 'static>
+    AbortableStreamingLoess<T>
+{
+    /// Process a chunk of data.
+    ///
+    /// Returns `Err(StreamingRunError::Aborted)` without touching the
+    /// processor if the paired [`AbortHandle::abort`] has been called.
+    pub fn process_chunk<I1, I2>(
+        &mut self,
+        x: &I1,
+        y: &I2,
+    ) -> Result<LoessResult<T>, StreamingRunError>
+    where
+        I1: LoessInput<T> + ?Sized,
+        I2: LoessInput<T> + ?Sized,
+    {
+        if self.aborted.load(Ordering::SeqCst) {
+            return Err(StreamingRunError::Aborted);
+        }
+
+        self.inner.process_chunk(x, y).map_err(StreamingRunError::Loess)
+    }
+
+    /// Finalize processing and get any remaining buffered data.
+    ///
+    /// Always returns whatever was already buffered, even if the run was
+    /// aborted, so callers get partial results rather than nothing.
+    pub fn finalize(&mut self) -> Result<LoessResult<T>, StreamingRunError> {
+        self.inner.finalize().map_err(StreamingRunError::Loess)
+    }
+
+    /// Reset the processor state.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
 }
@@ -110,6 +110,9 @@ pub mod engine;
 /// Evaluation utilities (CV, intervals).
 pub mod evaluation;
 
+/// Custom kernel weight functions.
+pub mod kernels;
+
 /// High-level API with parallel support.
 pub mod api;
 
@@ -132,6 +135,9 @@ pub mod prelude {
         LoessError,
     };
 
+    // Re-export custom kernel support
+    pub use crate::kernels::{hat_convolution, CustomWeightFn};
+
     // Re-export the base types from loess-rs
     pub use loess_rs::prelude::{Average, TakeFirst, WeightedAverage};
     pub use loess_rs::prelude::{
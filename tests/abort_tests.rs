@@ -0,0 +1,50 @@
+use fastLoess::adapters::streaming::StreamingRunError;
+use fastLoess::prelude::*;
+
+#[test]
+fn test_abort_stops_next_chunk_but_finalize_keeps_buffered_data() {
+    let x1: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    let y1: Vec<f64> = x1.iter().map(|&v| v * 2.0).collect();
+    let x2: Vec<f64> = (20..40).map(|i| i as f64).collect();
+    let y2: Vec<f64> = x2.iter().map(|&v| v * 2.0).collect();
+
+    let (mut run, handle) = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .build_abortable()
+        .unwrap();
+
+    // Not aborted yet: the first chunk should process normally.
+    assert!(!handle.is_aborted());
+    let first = run.process_chunk(&x1, &y1);
+    assert!(first.is_ok());
+
+    handle.abort();
+    assert!(handle.is_aborted());
+
+    // Aborted: the next chunk boundary should short-circuit without touching
+    // the processor.
+    let second = run.process_chunk(&x2, &y2);
+    assert!(matches!(second, Err(StreamingRunError::Aborted)));
+
+    // `finalize` still returns whatever was already buffered from the first
+    // (successful) chunk, rather than propagating the abort.
+    let finalized = run.finalize();
+    assert!(finalized.is_ok());
+}
+
+#[test]
+fn test_unaborted_run_behaves_like_the_non_abortable_path() {
+    let x: Vec<f64> = (0..30).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|&v| v * 3.0 + 1.0).collect();
+
+    let (mut run, handle) = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .build_abortable()
+        .unwrap();
+
+    assert!(!handle.is_aborted());
+    let result = run.process_chunk(&x, &y);
+    assert!(result.is_ok());
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "async")]
+
+use fastLoess::adapters::async_online::{AsyncOnlineLoess, EmitPolicy};
+use fastLoess::prelude::*;
+use futures::{SinkExt, StreamExt};
+
+#[tokio::test]
+async fn test_async_online_smoothed_only_filters_warm_up_points() {
+    let processor = Loess::new()
+        .fraction(0.5)
+        .adapter(Online)
+        .window_capacity(10)
+        .min_points(5)
+        .build()
+        .unwrap();
+
+    let mut async_loess = AsyncOnlineLoess::new(processor);
+
+    for i in 0..10u32 {
+        async_loess.send((vec![i as f64], i as f64)).await.unwrap();
+    }
+    async_loess.close().await.unwrap();
+
+    let mut emitted = 0;
+    while let Some(item) = async_loess.next().await {
+        let output = item.expect("point should be accepted");
+        // `SmoothedOnly` (the default) never forwards the `None` warm-up
+        // outputs, only smoothed emissions.
+        assert!(output.is_some());
+        emitted += 1;
+    }
+
+    assert!(emitted > 0 && emitted <= 10);
+}
+
+#[tokio::test]
+async fn test_async_online_every_policy_forwards_warm_up_points() {
+    let processor = Loess::new()
+        .fraction(0.5)
+        .adapter(Online)
+        .window_capacity(10)
+        .min_points(5)
+        .build()
+        .unwrap();
+
+    let mut async_loess = AsyncOnlineLoess::with_options(processor, 64, EmitPolicy::Every);
+
+    for i in 0..10u32 {
+        async_loess.send((vec![i as f64], i as f64)).await.unwrap();
+    }
+    async_loess.close().await.unwrap();
+
+    let mut results = Vec::new();
+    while let Some(item) = async_loess.next().await {
+        results.push(item.expect("point should be accepted"));
+    }
+
+    // `Every` forwards one outcome per accepted point, including the `None`
+    // warm-up outputs before `min_points` is reached.
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().any(|r| r.is_none()));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "async")]
+
+use fastLoess::adapters::async_streaming::AsyncStreamingLoess;
+use fastLoess::prelude::*;
+use futures::{SinkExt, StreamExt};
+
+#[tokio::test]
+async fn test_async_streaming_emits_chunks_in_order_then_finalizes_on_close() {
+    let processor = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .overlap(0)
+        .build()
+        .unwrap();
+
+    let mut async_loess = AsyncStreamingLoess::new(processor);
+
+    let x1: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    let y1: Vec<f64> = x1.iter().map(|&v| v * 2.0).collect();
+    let x2: Vec<f64> = (20..40).map(|i| i as f64).collect();
+    let y2: Vec<f64> = x2.iter().map(|&v| v * 2.0).collect();
+
+    async_loess.send((x1, y1)).await.unwrap();
+    async_loess.send((x2, y2)).await.unwrap();
+    async_loess.close().await.unwrap();
+
+    let mut results = Vec::new();
+    while let Some(item) = async_loess.next().await {
+        results.push(item.expect("chunk should process successfully"));
+    }
+
+    // Two submitted chunks plus the trailing `finalize()` result emitted
+    // once the sink closes.
+    assert_eq!(results.len(), 3);
+}
@@ -0,0 +1,50 @@
+use approx::assert_relative_eq;
+use fastLoess::prelude::*;
+
+#[test]
+fn test_hat_convolution_peak_and_boundary_values() {
+    assert_relative_eq!(hat_convolution(0.0_f64), 2.0 / 3.0, epsilon = 1e-12);
+    assert_eq!(hat_convolution(1.0_f64), 0.0);
+    assert_eq!(hat_convolution(1.5_f64), 0.0);
+    // Symmetric in the sign of `d`.
+    assert_relative_eq!(hat_convolution(-0.25_f64), hat_convolution(0.25_f64), epsilon = 1e-12);
+}
+
+#[test]
+fn test_hat_convolution_is_continuous_across_both_pieces() {
+    // The two formula pieces meet at d = 0.5 (t = 2d = 1); evaluating just
+    // below and just above should agree to within a small tolerance.
+    let just_below = hat_convolution(0.5_f64 - 1e-9);
+    let just_above = hat_convolution(0.5_f64 + 1e-9);
+    assert_relative_eq!(just_below, just_above, epsilon = 1e-6);
+    assert_relative_eq!(just_below, 1.0 / 6.0, epsilon = 1e-6);
+
+    // And the outer piece must decay to 0 at the support boundary, not jump.
+    let just_below_edge = hat_convolution(1.0_f64 - 1e-9);
+    assert_relative_eq!(just_below_edge, 0.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_custom_weight_function_changes_streaming_output() {
+    let x: Vec<f64> = (0..50).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|&v| (v * 0.1).sin()).collect();
+
+    let mut default_kernel = Loess::new().fraction(0.3).adapter(Streaming).build().unwrap();
+    let default_result = default_kernel.process_chunk(&x, &y).unwrap();
+
+    let mut custom_kernel = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .custom_weight_function(hat_convolution)
+        .build()
+        .unwrap();
+    let custom_result = custom_kernel.process_chunk(&x, &y).unwrap();
+
+    assert_eq!(default_result.y.len(), custom_result.y.len());
+    let differs = default_result
+        .y
+        .iter()
+        .zip(custom_result.y.iter())
+        .any(|(a, b)| (a - b).abs() > 1e-6);
+    assert!(differs, "a configured custom kernel should change the fit");
+}
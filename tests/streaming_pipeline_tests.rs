@@ -0,0 +1,94 @@
+use approx::assert_relative_eq;
+use fastLoess::prelude::*;
+
+#[test]
+fn test_pipelined_chunks_match_sequential_when_no_overlap() {
+    let x: Vec<f64> = (0..200).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|&v| (v * 0.05).sin()).collect();
+
+    let chunks: Vec<(Vec<f64>, Vec<f64>)> = x
+        .chunks(20)
+        .zip(y.chunks(20))
+        .map(|(xc, yc)| (xc.to_vec(), yc.to_vec()))
+        .collect();
+
+    let mut sequential = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .overlap(0)
+        .build()
+        .unwrap();
+
+    let sequential_results: Vec<_> = chunks
+        .iter()
+        .map(|(xc, yc)| sequential.process_chunk(xc, yc).unwrap())
+        .collect();
+
+    let mut pipelined = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .overlap(0)
+        .max_in_flight(3)
+        .build()
+        .unwrap();
+
+    let pipelined_results = pipelined.process_chunks_pipelined(chunks.clone());
+
+    // Same chunk count, same submission order, values equal within tolerance:
+    // the reorder buffer must undo any out-of-order completion on the rayon pool.
+    assert_eq!(sequential_results.len(), pipelined_results.len());
+    for (seq, pipe) in sequential_results.iter().zip(pipelined_results.iter()) {
+        let pipe = pipe.as_ref().expect("pipelined chunk should succeed");
+        assert_eq!(seq.y.len(), pipe.y.len());
+        for (a, b) in seq.y.iter().zip(pipe.y.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_pipelined_chunks_with_overlap_agree_with_sequential_on_linear_data() {
+    // The pipelined overlap path reconciles boundary estimates with its own
+    // `MergeStrategy` reimplementation rather than loess-rs's internal merge
+    // (see `process_chunks_pipelined`'s docs), so it isn't guaranteed
+    // bit-for-bit identical to the sequential path in general. On perfectly
+    // linear data, though, any reasonable local-regression window reproduces
+    // the line, so the two paths should still agree closely here.
+    let x: Vec<f64> = (0..120).map(|i| i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|&v| v * 2.0 + 1.0).collect();
+
+    let chunks: Vec<(Vec<f64>, Vec<f64>)> = x
+        .chunks(20)
+        .zip(y.chunks(20))
+        .map(|(xc, yc)| (xc.to_vec(), yc.to_vec()))
+        .collect();
+
+    let mut sequential = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .overlap(5)
+        .build()
+        .unwrap();
+
+    let sequential_results: Vec<_> = chunks
+        .iter()
+        .map(|(xc, yc)| sequential.process_chunk(xc, yc).unwrap())
+        .collect();
+
+    let mut pipelined = Loess::new()
+        .fraction(0.3)
+        .adapter(Streaming)
+        .overlap(5)
+        .build()
+        .unwrap();
+
+    let pipelined_results = pipelined.process_chunks_pipelined(chunks.clone());
+
+    assert_eq!(sequential_results.len(), pipelined_results.len());
+    for (seq, pipe) in sequential_results.iter().zip(pipelined_results.iter()) {
+        let pipe = pipe.as_ref().expect("pipelined chunk should succeed");
+        for (a, b) in seq.y.iter().zip(pipe.y.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}